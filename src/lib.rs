@@ -1,5 +1,33 @@
-use std::{error::Error, fmt::Display};
-use geo::{Coord, LineString};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Only the delta/zigzag/compact-int/bit-packed codec below (`encode_int`/`decode_int`,
+// `encode_compact_int`/`decode_compact_int`/`try_decode_compact_int`, `BitWriter`/`BitReader`,
+// and `CompLs`'s own byte-only methods: `try_new`, `size`, `try_new_compact`, `size_compact`,
+// `try_new_packed`, `size_packed`) is genuinely no_std + alloc only. Everything that takes or
+// returns a `geo` type (`CompLs::try_encode*`/`linestring*`, all of `CompGeom`, `ToCompLs`,
+// `ToCompGeom`, the `compls_p*`/`compgeom_*_p*` serde adapters) stays behind the `std` feature,
+// because `geo` 0.28 itself has no `no_std` support — gating just this crate's own `use`
+// statement can't change that. A manifest that wants true embedded/wasm no_std support needs
+// `geo` marked `optional = true` and pulled in only by `std = ["dep:geo"]`; `serde` stays a
+// required dependency, since `CompLs` derives `Serialize`/`Deserialize` without the feature.
+//
+// `CompLs`/`CompGeom` derive `Deserialize` on private byte fields with no custom validation,
+// so the decode paths reachable from `size_compact`/`size_packed`/`linestring_compact`/
+// `linestring_packed`/`geometry` assume `coords`/`bytes` came straight from
+// `bincode::deserialize` rather than a `try_new*`/`try_encode_*` constructor, and return
+// `CompLsError::BrokenEncoding` instead of indexing past the end or sizing allocations from
+// unchecked counts. A packed buffer whose two axes both have width 0 still decodes to as many
+// vertices as its header claims. The varint `size`/`linestring` pair returns no `Result` and
+// does no such checking.
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec, string::String, format};
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use geo::{Coord, LineString, MultiLineString, Polygon, MultiPolygon};
 use serde::{Serialize, Deserialize};
 
 /// How many digits the coordinates should be rounded to. `Two` works well for metric CRS like Pseudo-mercator EPSG:3857. `Seven` is needed for lat/lon coordinates (WGS-84 aka EPSG:4326). `Other` variant sets arbitrary precision.
@@ -45,19 +73,174 @@ fn decode_int(value: &[u8]) -> i64 {
 	!result / 2 * sign
 }
 
+// maps a signed delta to an unsigned value with small magnitudes (positive or negative)
+// staying small, so fixed-width/varint schemes don't have to special-case the sign bit.
+fn zigzag_encode(value: i64) -> u64 {
+	((value << 1) ^ (value >> 63)) as u64
+}
+
+fn zigzag_decode(zigzag: u64) -> i64 {
+	((zigzag >> 1) as i64) ^ -((zigzag & 1) as i64)
+}
+
+// width in bytes of a SCALE-style compact integer, given its first (header) byte.
+fn compact_int_width(header: u8) -> usize {
+	match header & 0b11 {
+		0b00 => 1,
+		0b01 => 2,
+		0b10 => 4,
+		_ => 1 + 4 + (header >> 2) as usize, // big-integer mode: upper 6 bits hold (byte_count - 4)
+	}
+}
+
+// encodes value as a SCALE-style compact integer: zigzag-map to u64, then pick the
+// narrowest of 1/2/4/N bytes and tag the mode in the low 2 bits of the first byte.
+// unlike `encode_int`, every byte here is pure payload, so there's no continuation-bit tax.
+fn encode_compact_int(value: i64, output: &mut Vec<u8>) {
+	let zigzag = zigzag_encode(value);
+	if zigzag <= 0x3f {
+		output.push((zigzag as u8) << 2);
+	} else if zigzag <= 0x3fff {
+		output.extend_from_slice(&(((zigzag as u16) << 2) | 0b01).to_le_bytes());
+	} else if zigzag <= 0x3fff_ffff {
+		output.extend_from_slice(&(((zigzag as u32) << 2) | 0b10).to_le_bytes());
+	} else {
+		let byte_count = (((64 - zigzag.leading_zeros()) as usize).div_ceil(8)).max(4);
+		output.push((((byte_count - 4) as u8) << 2) | 0b11);
+		output.extend_from_slice(&zigzag.to_le_bytes()[..byte_count]);
+	}
+}
+
+// decodes a SCALE-style compact integer, returning (value, bytes consumed). Trusts that
+// `bytes` holds at least as many bytes as the header demands; callers parsing untrusted
+// input should go through `try_decode_compact_int` instead.
+fn decode_compact_int(bytes: &[u8]) -> (i64, usize) {
+	let width = compact_int_width(bytes[0]);
+	let zigzag: u64 = match bytes[0] & 0b11 {
+		0b00 => (bytes[0] >> 2) as u64,
+		0b01 => (u16::from_le_bytes([bytes[0], bytes[1]]) >> 2) as u64,
+		0b10 => (u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) >> 2) as u64,
+		_ => {
+			let byte_count = width - 1;
+			let mut buf = [0_u8; 8];
+			buf[..byte_count].copy_from_slice(&bytes[1..width]);
+			u64::from_le_bytes(buf)
+		}
+	};
+	(zigzag_decode(zigzag), width)
+}
+
+// same as `decode_compact_int`, but bounds-checks against truncated input instead of
+// indexing past the end of `bytes`.
+fn try_decode_compact_int(bytes: &[u8]) -> Result<(i64, usize), CompLsError> {
+	let Some(&header) = bytes.first() else {
+		return Err(CompLsError::BrokenEncoding("compact integer truncated at end of buffer".into()));
+	};
+	let width = compact_int_width(header);
+	// big-int mode's byte count comes from the header's upper 6 bits (up to 63): a zigzag
+	// value never needs more than 8 bytes, so anything wider can't have come from
+	// `encode_compact_int` and would overflow `decode_compact_int`'s fixed-size buffer.
+	if width > 9 {
+		return Err(CompLsError::BrokenEncoding("compact integer width exceeds 8 bytes".into()));
+	}
+	if width > bytes.len() {
+		return Err(CompLsError::BrokenEncoding("compact integer truncated at end of buffer".into()));
+	}
+	Ok(decode_compact_int(bytes))
+}
+
+// minimal number of bits needed to hold an unsigned value (0 for 0).
+fn bit_width(value: u64) -> usize {
+	(64 - value.leading_zeros()) as usize
+}
+
+/// width (in bits) of the per-vertex field for one axis of a bit-packed delta stream: 0
+/// if every delta on this axis is identical (the constant is then stored once, outside
+/// the stream), otherwise the widest bit width among the zigzag-mapped deltas.
+fn packed_axis_width(zigzags: &[u64]) -> usize {
+	match zigzags.split_first() {
+		None => 0,
+		Some((first, rest)) => {
+			if rest.iter().all(|v| v == first) { 0 }
+			else { zigzags.iter().copied().map(bit_width).max().unwrap_or(0) }
+		}
+	}
+}
+
+// packs unsigned values into a continuous little-endian bit stream, LSB first, with no
+// per-field tags; the final byte is zero-padded.
+#[derive(Default)]
+struct BitWriter {
+	buf: Vec<u8>,
+	bitpos: usize,
+}
+
+impl BitWriter {
+	fn write_bits(&mut self, value: u64, width: usize) {
+		for i in 0..width {
+			let byte_idx = self.bitpos / 8;
+			if byte_idx == self.buf.len() { self.buf.push(0); }
+			if (value >> i) & 1 == 1 { self.buf[byte_idx] |= 1 << (self.bitpos % 8); }
+			self.bitpos += 1;
+		}
+	}
+}
+
+// reverses `BitWriter`: pulls fixed-width fields out of a little-endian, LSB-first bit stream.
+struct BitReader<'a> {
+	buf: &'a [u8],
+	bitpos: usize,
+}
+
+impl<'a> BitReader<'a> {
+	fn new(buf: &'a [u8]) -> Self { Self { buf, bitpos: 0 } }
+
+	// bounds-checked: `buf` may be shorter than the header promises when it reached here
+	// via derived `Deserialize` instead of `CompLs::try_new_packed`.
+	fn read_bits(&mut self, width: usize) -> Result<u64, CompLsError> {
+		let mut value = 0_u64;
+		for i in 0..width {
+			let byte_idx = self.bitpos / 8;
+			let &byte = self.buf.get(byte_idx).ok_or_else(|| CompLsError::BrokenEncoding("packed bit stream truncated".into()))?;
+			let bit = (byte >> (self.bitpos % 8)) & 1;
+			value |= (bit as u64) << i;
+			self.bitpos += 1;
+		}
+		Ok(value)
+	}
+}
+
+// parsed header of a `try_encode_packed` buffer: per-axis bit width, the repeated delta
+// when that axis collapsed to width 0, and how many leading bytes the header occupies.
+struct PackedHeader {
+	count: usize,
+	bx: usize,
+	const_x: Option<i64>,
+	by: usize,
+	const_y: Option<i64>,
+	header_len: usize,
+}
+
 
 #[derive(Debug, Clone)]
 pub enum CompLsError {
 	EmptyLineString,
 	BrokenLineString(String),
 	BrokenEncoding(String),
+	Io(String),
 }
 
 impl Display for CompLsError {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "{:?}", &self) }
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result { write!(f, "{:?}", &self) }
 }
+#[cfg(feature = "std")]
 impl Error for CompLsError {}
 
+#[cfg(feature = "std")]
+impl From<std::io::Error> for CompLsError {
+	fn from(e: std::io::Error) -> Self { Self::Io(e.to_string()) }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompLs {
 	coords: Vec<u8>
@@ -78,14 +261,17 @@ impl CompLs {
 		self.coords.iter().filter(|v| **v < 128).count() >> 1 // >>1 is integer division by 2
 	}
 
+	#[cfg(feature = "std")]
 	pub fn try_encode2(value: &LineString) -> Result<Self, CompLsError> {
 		Self::try_encode(value, Precision::Two)
 	}
 
+	#[cfg(feature = "std")]
 	pub fn try_encode7(value: &LineString) -> Result<Self, CompLsError> {
 		Self::try_encode(value, Precision::Seven)
 	}
 
+	#[cfg(feature = "std")]
 	pub fn try_encode(value: &LineString, precision: Precision) -> Result<Self, CompLsError> {
 		let m = precision.multiplicator();
 
@@ -103,6 +289,228 @@ impl CompLs {
 		Ok(Self { coords })
 	}
 
+	/// Like [`Self::try_new`], but validates bytes produced by [`Self::try_encode_compact`]:
+	/// each coordinate carries its own width in its mode tag, so this just walks the tags
+	/// instead of counting terminator bytes.
+	pub fn try_new_compact(coords: &[u8]) -> Result<Self, CompLsError> {
+		let mut i = 0_usize;
+		let mut count = 0_usize;
+		while i < coords.len() {
+			let (_, width) = try_decode_compact_int(&coords[i..])?;
+			i += width;
+			count += 1;
+		}
+		if count & 1 == 1 {
+			return Err(CompLsError::BrokenEncoding("number of coordinates in encoding is odd, must be even".into()));
+		}
+		Ok(Self { coords: coords.into() })
+	}
+
+	/// Number of coordinates encoded by [`Self::try_encode_compact`]. Counterpart to
+	/// [`Self::size`], which only understands the varint format from [`Self::try_encode`].
+	pub fn size_compact(&self) -> Result<usize, CompLsError> {
+		let mut i = 0_usize;
+		let mut count = 0_usize;
+		while i < self.coords.len() {
+			let (_, width) = try_decode_compact_int(&self.coords[i..])?;
+			i += width;
+			count += 1;
+		}
+		Ok(count >> 1)
+	}
+
+	/// Alternative to [`Self::try_encode`] that stores each delta as a SCALE-style compact
+	/// integer (a 2-bit mode tag picks a 1/2/4/N byte width) instead of a LEB128-style
+	/// varint. Small deltas still cost one byte, but the common 8-30 bit range costs 2 or 4
+	/// bytes instead of 2-5, since no bits are spent on continuation. Decode the result with
+	/// [`Self::linestring_compact`].
+	#[cfg(feature = "std")]
+	pub fn try_encode_compact(value: &LineString, precision: Precision) -> Result<Self, CompLsError> {
+		let m = precision.multiplicator();
+
+		let mut prev = &Coord { x: 0.0, y: 0.0 };
+		let mut coords: Vec<u8> = vec![];
+		for c in value.0.iter() {
+			let Coord { mut x, mut y } = *c - *prev;
+			if x.is_nan() || x.is_infinite() || y.is_nan() || y.is_infinite() { return Err(CompLsError::BrokenLineString("x or y coord is infinite".into())) }
+			if x < 0.0 { x -= 1.0 / m; }
+			if y < 0.0 { y -= 1.0 / m; }
+			encode_compact_int((x * m).round() as i64, &mut coords);
+			encode_compact_int((y * m).round() as i64, &mut coords);
+			prev = c;
+		}
+		Ok(Self { coords })
+	}
+
+	/// Decodes bytes produced by [`Self::try_encode_compact`]. Do not call this on bytes
+	/// from [`Self::try_encode`] (or vice versa) — the two modes are not self-describing
+	/// against each other, the caller must remember which one was used.
+	#[cfg(feature = "std")]
+	pub fn linestring_compact(&self, precision: Precision) -> Result<LineString, CompLsError> {
+		let multi = precision.multiplicator();
+		let mut ls = LineString(Vec::with_capacity(self.size_compact()?));
+		let mut prev = Coord { x: 0.0, y: 0.0 };
+
+		let mut i = 0_usize;
+		while i < self.coords.len() {
+			let (dx, wx) = try_decode_compact_int(&self.coords[i..])?;
+			i += wx;
+			let (dy, wy) = try_decode_compact_int(&self.coords[i..])?;
+			i += wy;
+
+			let mut x = dx as f64 / multi;
+			let mut y = dy as f64 / multi;
+			if x < 0.0 { x += 1.0 / multi }
+			if y < 0.0 { y += 1.0 / multi }
+
+			let c = Coord { x, y } + prev;
+			ls.0.push(c);
+			prev = c;
+		}
+		Ok(ls)
+	}
+
+	/// Alternative to [`Self::try_encode`]/[`Self::try_encode_compact`] for dense
+	/// linestrings with many short, similar segments: instead of byte-aligned fields per
+	/// coordinate, this picks one bit width per axis (wide enough for the widest delta on
+	/// that axis) and packs every delta into a continuous bit stream with no per-field
+	/// tags. An axis whose delta never changes, the first delta (measured from (0, 0))
+	/// included, costs 0 bits per vertex — the repeated value is stored once in the header
+	/// instead. An axis-aligned linestring does not collapse that way unless its constant
+	/// coordinate is 0: its first delta on that axis is the coordinate itself and the rest
+	/// are 0. Decode the result with [`Self::linestring_packed`].
+	#[cfg(feature = "std")]
+	pub fn try_encode_packed(value: &LineString, precision: Precision) -> Result<Self, CompLsError> {
+		let m = precision.multiplicator();
+
+		let mut prev = Coord { x: 0.0, y: 0.0 };
+		let mut dxs: Vec<i64> = Vec::with_capacity(value.0.len());
+		let mut dys: Vec<i64> = Vec::with_capacity(value.0.len());
+		for c in value.0.iter() {
+			let Coord { mut x, mut y } = *c - prev;
+			if x.is_nan() || x.is_infinite() || y.is_nan() || y.is_infinite() { return Err(CompLsError::BrokenLineString("x or y coord is infinite".into())) }
+			if x < 0.0 { x -= 1.0 / m; }
+			if y < 0.0 { y -= 1.0 / m; }
+			dxs.push((x * m).round() as i64);
+			dys.push((y * m).round() as i64);
+			prev = *c;
+		}
+
+		let zx: Vec<u64> = dxs.iter().copied().map(zigzag_encode).collect();
+		let zy: Vec<u64> = dys.iter().copied().map(zigzag_encode).collect();
+		let bx = packed_axis_width(&zx);
+		let by = packed_axis_width(&zy);
+
+		let mut header: Vec<u8> = vec![];
+		encode_compact_int(dxs.len() as i64, &mut header); // vertex count
+		header.push(bx as u8);
+		if bx == 0 && !dxs.is_empty() { encode_compact_int(dxs[0], &mut header); }
+		header.push(by as u8);
+		if by == 0 && !dys.is_empty() { encode_compact_int(dys[0], &mut header); }
+
+		let mut bits = BitWriter::default();
+		if bx > 0 { for &v in &zx { bits.write_bits(v, bx); } }
+		if by > 0 { for &v in &zy { bits.write_bits(v, by); } }
+		header.extend_from_slice(&bits.buf);
+
+		Ok(Self { coords: header })
+	}
+
+	/// Validates bytes produced by [`Self::try_encode_packed`]: the header alone pins down
+	/// exactly how many payload bytes the bit stream must occupy, so this checks that the
+	/// buffer is neither truncated nor padded with trailing garbage.
+	pub fn try_new_packed(coords: &[u8]) -> Result<Self, CompLsError> {
+		let header = Self::parse_packed_header(coords)?;
+		let total_bits = header.count.checked_mul(header.bx + header.by)
+			.ok_or_else(|| CompLsError::BrokenEncoding("packed vertex count overflows bit stream length".into()))?;
+		let payload_bytes = total_bits.div_ceil(8);
+		if coords.len() - header.header_len != payload_bytes {
+			return Err(CompLsError::BrokenEncoding("packed bit stream length does not match header".into()));
+		}
+		Ok(Self { coords: coords.into() })
+	}
+
+	/// Number of coordinates encoded by [`Self::try_encode_packed`], read from the header
+	/// alone — no need to touch the bit-packed payload.
+	pub fn size_packed(&self) -> Result<usize, CompLsError> {
+		Ok(Self::parse_packed_header(&self.coords)?.count)
+	}
+
+	// parses the header written by `try_encode_packed`.
+	fn parse_packed_header(coords: &[u8]) -> Result<PackedHeader, CompLsError> {
+		let mut pos = 0_usize;
+		let (count_i, consumed) = try_decode_compact_int(coords)?;
+		pos += consumed;
+		if count_i < 0 {
+			return Err(CompLsError::BrokenEncoding("packed vertex count is negative".into()));
+		}
+		let count = count_i as usize;
+
+		let Some(&bx_byte) = coords.get(pos) else {
+			return Err(CompLsError::BrokenEncoding("packed header truncated (missing x width)".into()));
+		};
+		let bx = bx_byte as usize;
+		if bx > 64 {
+			return Err(CompLsError::BrokenEncoding("packed x width exceeds 64 bits".into()));
+		}
+		pos += 1;
+		let const_x = if bx == 0 && count > 0 {
+			let (v, consumed) = try_decode_compact_int(&coords[pos..])?;
+			pos += consumed;
+			Some(v)
+		} else { None };
+
+		let Some(&by_byte) = coords.get(pos) else {
+			return Err(CompLsError::BrokenEncoding("packed header truncated (missing y width)".into()));
+		};
+		let by = by_byte as usize;
+		if by > 64 {
+			return Err(CompLsError::BrokenEncoding("packed y width exceeds 64 bits".into()));
+		}
+		pos += 1;
+		let const_y = if by == 0 && count > 0 {
+			let (v, consumed) = try_decode_compact_int(&coords[pos..])?;
+			pos += consumed;
+			Some(v)
+		} else { None };
+
+		Ok(PackedHeader { count, bx, const_x, by, const_y, header_len: pos })
+	}
+
+	/// Decodes bytes produced by [`Self::try_encode_packed`]. As with the compact mode, the
+	/// caller must remember which encoding mode a given buffer was produced with.
+	#[cfg(feature = "std")]
+	pub fn linestring_packed(&self, precision: Precision) -> Result<LineString, CompLsError> {
+		let multi = precision.multiplicator();
+		let coords = &self.coords;
+
+		let header = Self::parse_packed_header(coords)?;
+		let mut reader = BitReader::new(&coords[header.header_len..]);
+
+		// the bit stream is columnar (all x-deltas, then all y-deltas), mirroring `try_encode_packed`.
+		let dxs: Vec<i64> = (0..header.count).map(|_| {
+			if header.bx == 0 { Ok(header.const_x.unwrap()) } else { reader.read_bits(header.bx).map(zigzag_decode) }
+		}).collect::<Result<_, CompLsError>>()?;
+		let dys: Vec<i64> = (0..header.count).map(|_| {
+			if header.by == 0 { Ok(header.const_y.unwrap()) } else { reader.read_bits(header.by).map(zigzag_decode) }
+		}).collect::<Result<_, CompLsError>>()?;
+
+		let mut ls = LineString(Vec::with_capacity(header.count));
+		let mut prev = Coord { x: 0.0, y: 0.0 };
+		for (dx, dy) in dxs.into_iter().zip(dys) {
+			let mut x = dx as f64 / multi;
+			let mut y = dy as f64 / multi;
+			if x < 0.0 { x += 1.0 / multi }
+			if y < 0.0 { y += 1.0 / multi }
+
+			let c = Coord { x, y } + prev;
+			ls.0.push(c);
+			prev = c;
+		}
+		Ok(ls)
+	}
+
+	#[cfg(feature = "std")]
 	pub fn linestring(&self, precision: Precision) -> LineString {
 		let multi = precision.multiplicator();
 		// let capacity = (self.coords.len() - 6) >> 2;
@@ -135,6 +543,474 @@ impl CompLs {
 
 }
 
+/// Streams a linestring's varint-encoded deltas straight to `w`, one coordinate at a
+/// time, instead of building a [`CompLs`] in memory first. Useful for serializing large
+/// collections of geometries to a file or socket with bounded memory.
+#[cfg(feature = "std")]
+pub fn encode_to<W: std::io::Write>(value: &LineString, precision: Precision, w: &mut W) -> Result<(), CompLsError> {
+	let m = precision.multiplicator();
+
+	let mut prev = Coord { x: 0.0, y: 0.0 };
+	let mut coords: Vec<u8> = vec![];
+	for c in value.0.iter() {
+		let Coord { mut x, mut y } = *c - prev;
+		if x.is_nan() || x.is_infinite() || y.is_nan() || y.is_infinite() { return Err(CompLsError::BrokenLineString("x or y coord is infinite".into())) }
+		if x < 0.0 { x -= 1.0 / m; }
+		if y < 0.0 { y -= 1.0 / m; }
+		coords.clear();
+		encode_int((x * m).round() as i64, &mut coords);
+		encode_int((y * m).round() as i64, &mut coords);
+		w.write_all(&coords)?;
+		prev = *c;
+	}
+	Ok(())
+}
+
+/// Lazily decodes varint-encoded deltas read from `r` one coordinate at a time, without
+/// materializing the whole byte buffer or `LineString` up front. Returned by
+/// [`decode_from`]; yields `Err(CompLsError::BrokenEncoding(_))` if a varint is truncated
+/// at EOF, and `Err(CompLsError::Io(_))` on an underlying read failure.
+#[cfg(feature = "std")]
+pub struct CompLsDecoder<'r, R: std::io::Read> {
+	reader: &'r mut R,
+	prev: Coord,
+	multi: f64,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> CompLsDecoder<'_, R> {
+	// reads one varint, or None at a clean EOF (no bytes read before it).
+	fn read_varint(&mut self) -> Result<Option<i64>, CompLsError> {
+		let mut bytes: Vec<u8> = vec![];
+		loop {
+			let mut byte = [0_u8; 1];
+			let n = self.reader.read(&mut byte)?;
+			if n == 0 {
+				if bytes.is_empty() { return Ok(None); }
+				return Err(CompLsError::BrokenEncoding("varint truncated at EOF".into()));
+			}
+			let is_last = byte[0] < 128;
+			bytes.push(byte[0]);
+			if is_last { break; }
+		}
+		Ok(Some(decode_int(&bytes)))
+	}
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> Iterator for CompLsDecoder<'_, R> {
+	type Item = Result<Coord, CompLsError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let dx = match self.read_varint() {
+			Ok(None) => return None,
+			Ok(Some(v)) => v,
+			Err(e) => return Some(Err(e)),
+		};
+		let dy = match self.read_varint() {
+			Ok(Some(v)) => v,
+			Ok(None) => return Some(Err(CompLsError::BrokenEncoding("odd number of varints in stream".into()))),
+			Err(e) => return Some(Err(e)),
+		};
+
+		let mut x = dx as f64 / self.multi;
+		let mut y = dy as f64 / self.multi;
+		if x < 0.0 { x += 1.0 / self.multi }
+		if y < 0.0 { y += 1.0 / self.multi }
+
+		let c = Coord { x, y } + self.prev;
+		self.prev = c;
+		Some(Ok(c))
+	}
+}
+
+/// Counterpart to [`encode_to`]: wraps `r` in a [`CompLsDecoder`] that yields coordinates
+/// lazily, reconstructing the running delta as it goes, instead of reading everything
+/// into a `Vec<u8>` first.
+#[cfg(feature = "std")]
+pub fn decode_from<R: std::io::Read>(r: &mut R, precision: Precision) -> CompLsDecoder<'_, R> {
+	CompLsDecoder { reader: r, prev: Coord { x: 0.0, y: 0.0 }, multi: precision.multiplicator() }
+}
+
+// `CompGeom` and everything below it extends `CompLs`'s scheme to `geo::MultiLineString`/
+// `Polygon`/`MultiPolygon`, so (like `CompLs::try_encode*`/`linestring*` above) it stays
+// behind the `std` feature until `geo` itself supports no_std — see the module-level note
+// at the top of this file.
+#[cfg(feature = "std")]
+const COMP_GEOM_TAG_MULTI_LINE_STRING: u8 = 0;
+#[cfg(feature = "std")]
+const COMP_GEOM_TAG_POLYGON: u8 = 1;
+#[cfg(feature = "std")]
+const COMP_GEOM_TAG_MULTI_POLYGON: u8 = 2;
+
+// encodes one ring/part's vertex count (as a compact int) followed by its coordinates,
+// delta+varint encoded the same way `CompLs::try_encode` does. `prev` is threaded in and
+// out so that the first vertex of the *next* ring is delta-encoded against the *last*
+// vertex of this one, instead of resetting to (0, 0) at every ring boundary.
+#[cfg(feature = "std")]
+fn encode_ring(ring: &LineString, m: f64, prev: &mut Coord, out: &mut Vec<u8>) -> Result<(), CompLsError> {
+	encode_compact_int(ring.0.len() as i64, out);
+	for c in ring.0.iter() {
+		let Coord { mut x, mut y } = *c - *prev;
+		if x.is_nan() || x.is_infinite() || y.is_nan() || y.is_infinite() { return Err(CompLsError::BrokenLineString("x or y coord is infinite".into())) }
+		if x < 0.0 { x -= 1.0 / m; }
+		if y < 0.0 { y -= 1.0 / m; }
+		encode_int((x * m).round() as i64, out);
+		encode_int((y * m).round() as i64, out);
+		*prev = *c;
+	}
+	Ok(())
+}
+
+// reverses `encode_ring`: reads a vertex count then that many delta-varint coordinates,
+// starting from `*pos` and leaving `*pos` right after the ring's bytes.
+#[cfg(feature = "std")]
+fn decode_ring(bytes: &[u8], pos: &mut usize, multi: f64, prev: &mut Coord) -> Result<LineString, CompLsError> {
+	let rest = bytes.get(*pos..).ok_or_else(|| CompLsError::BrokenEncoding("ring header truncated".into()))?;
+	let (count_i, consumed) = try_decode_compact_int(rest)?;
+	*pos += consumed;
+	if count_i < 0 {
+		return Err(CompLsError::BrokenEncoding("ring vertex count is negative".into()));
+	}
+
+	let mut coords = Vec::with_capacity((count_i as usize).min(bytes.len() - *pos));
+	for _ in 0..count_i {
+		let mut x = read_varint(bytes, pos)? as f64 / multi;
+		let mut y = read_varint(bytes, pos)? as f64 / multi;
+		if x < 0.0 { x += 1.0 / multi }
+		if y < 0.0 { y += 1.0 / multi }
+
+		let c = Coord { x, y } + *prev;
+		coords.push(c);
+		*prev = c;
+	}
+	Ok(LineString(coords))
+}
+
+// scans forward from `*pos` for a LEB128-style varint (as written by `encode_int`),
+// decodes it, and advances `*pos` past it.
+#[cfg(feature = "std")]
+fn read_varint(bytes: &[u8], pos: &mut usize) -> Result<i64, CompLsError> {
+	let start = *pos;
+	let mut end = start;
+	loop {
+		let &byte = bytes.get(end).ok_or_else(|| CompLsError::BrokenEncoding("varint truncated at end of buffer".into()))?;
+		if byte < 128 { break; }
+		end += 1;
+	}
+	*pos = end + 1;
+	Ok(decode_int(&bytes[start..=end]))
+}
+
+/// A geometry decoded from [`CompGeom::geometry`]. Which variant comes back depends on
+/// the tag byte written by whichever `try_encode_*` constructor produced the bytes.
+#[derive(Debug, Clone)]
+#[cfg(feature = "std")]
+pub enum DecodedGeom {
+	MultiLineString(MultiLineString),
+	Polygon(Polygon),
+	MultiPolygon(MultiPolygon),
+}
+
+/// Compressed representation of a [`MultiLineString`], [`Polygon`], or [`MultiPolygon`],
+/// extending [`CompLs`]'s single-linestring delta+varint scheme to geometries made of
+/// several rings or parts. A leading tag byte identifies the geometry kind, followed by a
+/// compact count of rings/parts and then each ring in turn — with the running `prev`
+/// coordinate carried *across* ring boundaries, so nearby rings (as in OSM administrative
+/// boundaries) stay cheap to encode even though they're logically separate rings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg(feature = "std")]
+pub struct CompGeom {
+	bytes: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl CompGeom {
+	pub fn try_encode_multi_line_string(value: &MultiLineString, precision: Precision) -> Result<Self, CompLsError> {
+		let m = precision.multiplicator();
+		let mut prev = Coord { x: 0.0, y: 0.0 };
+		let mut bytes = vec![COMP_GEOM_TAG_MULTI_LINE_STRING];
+		encode_compact_int(value.0.len() as i64, &mut bytes);
+		for part in value.0.iter() {
+			encode_ring(part, m, &mut prev, &mut bytes)?;
+		}
+		Ok(Self { bytes })
+	}
+
+	pub fn try_encode_polygon(value: &Polygon, precision: Precision) -> Result<Self, CompLsError> {
+		let m = precision.multiplicator();
+		let mut prev = Coord { x: 0.0, y: 0.0 };
+		let mut bytes = vec![COMP_GEOM_TAG_POLYGON];
+		encode_compact_int((1 + value.interiors().len()) as i64, &mut bytes);
+		encode_ring(value.exterior(), m, &mut prev, &mut bytes)?;
+		for hole in value.interiors() {
+			encode_ring(hole, m, &mut prev, &mut bytes)?;
+		}
+		Ok(Self { bytes })
+	}
+
+	pub fn try_encode_multi_polygon(value: &MultiPolygon, precision: Precision) -> Result<Self, CompLsError> {
+		let m = precision.multiplicator();
+		let mut prev = Coord { x: 0.0, y: 0.0 };
+		let mut bytes = vec![COMP_GEOM_TAG_MULTI_POLYGON];
+		encode_compact_int(value.0.len() as i64, &mut bytes);
+		for poly in value.0.iter() {
+			encode_compact_int((1 + poly.interiors().len()) as i64, &mut bytes);
+			encode_ring(poly.exterior(), m, &mut prev, &mut bytes)?;
+			for hole in poly.interiors() {
+				encode_ring(hole, m, &mut prev, &mut bytes)?;
+			}
+		}
+		Ok(Self { bytes })
+	}
+
+	/// Decodes bytes produced by any of the `try_encode_*` constructors above, dispatching
+	/// on the leading tag byte the matching encoder wrote.
+	pub fn geometry(&self, precision: Precision) -> Result<DecodedGeom, CompLsError> {
+		let multi = precision.multiplicator();
+		let bytes = &self.bytes;
+		let Some(&tag) = bytes.first() else {
+			return Err(CompLsError::BrokenEncoding("empty CompGeom buffer".into()));
+		};
+		let mut pos = 1_usize;
+		let mut prev = Coord { x: 0.0, y: 0.0 };
+
+		match tag {
+			COMP_GEOM_TAG_MULTI_LINE_STRING => {
+				let (count, consumed) = try_decode_compact_int(&bytes[pos..])?;
+				pos += consumed;
+				if count < 0 {
+					return Err(CompLsError::BrokenEncoding("part count is negative".into()));
+				}
+				let parts = (0..count).map(|_| decode_ring(bytes, &mut pos, multi, &mut prev)).collect::<Result<_, _>>()?;
+				Ok(DecodedGeom::MultiLineString(MultiLineString(parts)))
+			}
+			COMP_GEOM_TAG_POLYGON => {
+				let (ring_count, consumed) = try_decode_compact_int(&bytes[pos..])?;
+				pos += consumed;
+				if ring_count < 1 {
+					return Err(CompLsError::BrokenEncoding("polygon needs at least an exterior ring".into()));
+				}
+				let exterior = decode_ring(bytes, &mut pos, multi, &mut prev)?;
+				let interiors = (1..ring_count).map(|_| decode_ring(bytes, &mut pos, multi, &mut prev)).collect::<Result<_, _>>()?;
+				Ok(DecodedGeom::Polygon(Polygon::new(exterior, interiors)))
+			}
+			COMP_GEOM_TAG_MULTI_POLYGON => {
+				let (poly_count, consumed) = try_decode_compact_int(&bytes[pos..])?;
+				pos += consumed;
+				if poly_count < 0 {
+					return Err(CompLsError::BrokenEncoding("polygon count is negative".into()));
+				}
+				let mut polys = Vec::with_capacity((poly_count as usize).min(bytes.len() - pos));
+				for _ in 0..poly_count {
+					let (ring_count, consumed) = try_decode_compact_int(&bytes[pos..])?;
+					pos += consumed;
+					if ring_count < 1 {
+						return Err(CompLsError::BrokenEncoding("polygon needs at least an exterior ring".into()));
+					}
+					let exterior = decode_ring(bytes, &mut pos, multi, &mut prev)?;
+					let interiors = (1..ring_count).map(|_| decode_ring(bytes, &mut pos, multi, &mut prev)).collect::<Result<_, _>>()?;
+					polys.push(Polygon::new(exterior, interiors));
+				}
+				Ok(DecodedGeom::MultiPolygon(MultiPolygon(polys)))
+			}
+			_ => Err(CompLsError::BrokenEncoding(format!("unknown CompGeom tag byte {tag}"))),
+		}
+	}
+}
+
+/// Convenience trait wrapping a function call, mirroring [`ToCompLs`] for the composite
+/// geometry kinds handled by [`CompGeom`].
+#[cfg(feature = "std")]
+pub trait ToCompGeom {
+	fn try_compact(&self, precision: Precision) -> Result<CompGeom, CompLsError>;
+	fn try_compact2(&self) -> Result<CompGeom, CompLsError>;
+	fn try_compact7(&self) -> Result<CompGeom, CompLsError>;
+}
+
+#[cfg(feature = "std")]
+impl ToCompGeom for MultiLineString {
+	fn try_compact(&self, precision: Precision) -> Result<CompGeom, CompLsError> {
+		CompGeom::try_encode_multi_line_string(self, precision)
+	}
+	fn try_compact2(&self) -> Result<CompGeom, CompLsError> {
+		CompGeom::try_encode_multi_line_string(self, Precision::Two)
+	}
+	fn try_compact7(&self) -> Result<CompGeom, CompLsError> {
+		CompGeom::try_encode_multi_line_string(self, Precision::Seven)
+	}
+}
+
+#[cfg(feature = "std")]
+impl ToCompGeom for Polygon {
+	fn try_compact(&self, precision: Precision) -> Result<CompGeom, CompLsError> {
+		CompGeom::try_encode_polygon(self, precision)
+	}
+	fn try_compact2(&self) -> Result<CompGeom, CompLsError> {
+		CompGeom::try_encode_polygon(self, Precision::Two)
+	}
+	fn try_compact7(&self) -> Result<CompGeom, CompLsError> {
+		CompGeom::try_encode_polygon(self, Precision::Seven)
+	}
+}
+
+#[cfg(feature = "std")]
+impl ToCompGeom for MultiPolygon {
+	fn try_compact(&self, precision: Precision) -> Result<CompGeom, CompLsError> {
+		CompGeom::try_encode_multi_polygon(self, precision)
+	}
+	fn try_compact2(&self) -> Result<CompGeom, CompLsError> {
+		CompGeom::try_encode_multi_polygon(self, Precision::Two)
+	}
+	fn try_compact7(&self) -> Result<CompGeom, CompLsError> {
+		CompGeom::try_encode_multi_polygon(self, Precision::Seven)
+	}
+}
+
+#[cfg(feature = "std")]
+pub mod compgeom_mls_p2 {
+	use serde::{Deserialize, Serializer, Deserializer, Serialize};
+	use geo::MultiLineString;
+	use super::{CompGeom, DecodedGeom, ToCompGeom};
+
+	pub fn serialize<S>(g: &MultiLineString, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer,
+	{
+		let s = g.try_compact2().map_err(serde::ser::Error::custom)?;
+		s.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<MultiLineString, D::Error>
+	where D: Deserializer<'de>,
+	{
+		let g = CompGeom::deserialize(deserializer)?;
+		match g.geometry(crate::Precision::Two).map_err(serde::de::Error::custom)? {
+			DecodedGeom::MultiLineString(mls) => Ok(mls),
+			_ => Err(serde::de::Error::custom("CompGeom buffer does not hold a MultiLineString")),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+pub mod compgeom_mls_p7 {
+	use serde::{Deserialize, Serializer, Deserializer, Serialize};
+	use geo::MultiLineString;
+	use super::{CompGeom, DecodedGeom, ToCompGeom};
+
+	pub fn serialize<S>(g: &MultiLineString, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer,
+	{
+		let s = g.try_compact7().map_err(serde::ser::Error::custom)?;
+		s.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<MultiLineString, D::Error>
+	where D: Deserializer<'de>,
+	{
+		let g = CompGeom::deserialize(deserializer)?;
+		match g.geometry(crate::Precision::Seven).map_err(serde::de::Error::custom)? {
+			DecodedGeom::MultiLineString(mls) => Ok(mls),
+			_ => Err(serde::de::Error::custom("CompGeom buffer does not hold a MultiLineString")),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+pub mod compgeom_polygon_p2 {
+	use serde::{Deserialize, Serializer, Deserializer, Serialize};
+	use geo::Polygon;
+	use super::{CompGeom, DecodedGeom, ToCompGeom};
+
+	pub fn serialize<S>(g: &Polygon, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer,
+	{
+		let s = g.try_compact2().map_err(serde::ser::Error::custom)?;
+		s.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Polygon, D::Error>
+	where D: Deserializer<'de>,
+	{
+		let g = CompGeom::deserialize(deserializer)?;
+		match g.geometry(crate::Precision::Two).map_err(serde::de::Error::custom)? {
+			DecodedGeom::Polygon(p) => Ok(p),
+			_ => Err(serde::de::Error::custom("CompGeom buffer does not hold a Polygon")),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+pub mod compgeom_polygon_p7 {
+	use serde::{Deserialize, Serializer, Deserializer, Serialize};
+	use geo::Polygon;
+	use super::{CompGeom, DecodedGeom, ToCompGeom};
+
+	pub fn serialize<S>(g: &Polygon, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer,
+	{
+		let s = g.try_compact7().map_err(serde::ser::Error::custom)?;
+		s.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Polygon, D::Error>
+	where D: Deserializer<'de>,
+	{
+		let g = CompGeom::deserialize(deserializer)?;
+		match g.geometry(crate::Precision::Seven).map_err(serde::de::Error::custom)? {
+			DecodedGeom::Polygon(p) => Ok(p),
+			_ => Err(serde::de::Error::custom("CompGeom buffer does not hold a Polygon")),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+pub mod compgeom_mpoly_p2 {
+	use serde::{Deserialize, Serializer, Deserializer, Serialize};
+	use geo::MultiPolygon;
+	use super::{CompGeom, DecodedGeom, ToCompGeom};
+
+	pub fn serialize<S>(g: &MultiPolygon, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer,
+	{
+		let s = g.try_compact2().map_err(serde::ser::Error::custom)?;
+		s.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<MultiPolygon, D::Error>
+	where D: Deserializer<'de>,
+	{
+		let g = CompGeom::deserialize(deserializer)?;
+		match g.geometry(crate::Precision::Two).map_err(serde::de::Error::custom)? {
+			DecodedGeom::MultiPolygon(mp) => Ok(mp),
+			_ => Err(serde::de::Error::custom("CompGeom buffer does not hold a MultiPolygon")),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+pub mod compgeom_mpoly_p7 {
+	use serde::{Deserialize, Serializer, Deserializer, Serialize};
+	use geo::MultiPolygon;
+	use super::{CompGeom, DecodedGeom, ToCompGeom};
+
+	pub fn serialize<S>(g: &MultiPolygon, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer,
+	{
+		let s = g.try_compact7().map_err(serde::ser::Error::custom)?;
+		s.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<MultiPolygon, D::Error>
+	where D: Deserializer<'de>,
+	{
+		let g = CompGeom::deserialize(deserializer)?;
+		match g.geometry(crate::Precision::Seven).map_err(serde::de::Error::custom)? {
+			DecodedGeom::MultiPolygon(mp) => Ok(mp),
+			_ => Err(serde::de::Error::custom("CompGeom buffer does not hold a MultiPolygon")),
+		}
+	}
+}
+
 /// Convenience trait wrapping a function call. Allows instead of this:
 ///
 ///    CompLs::try_encode(&my_linestring)?
@@ -142,12 +1018,14 @@ impl CompLs {
 /// to write this:
 ///
 ///    my_linestring.try_compact()?
+#[cfg(feature = "std")]
 pub trait ToCompLs {
 	fn try_compact(&self, precision: Precision) -> Result<CompLs, CompLsError>;
 	fn try_compact2(&self) -> Result<CompLs, CompLsError>;
 	fn try_compact7(&self) -> Result<CompLs, CompLsError>;
 }
 
+#[cfg(feature = "std")]
 impl ToCompLs for LineString {
 	fn try_compact2(&self) -> Result<CompLs, CompLsError> {
 		CompLs::try_encode(self, Precision::Two)
@@ -160,6 +1038,7 @@ impl ToCompLs for LineString {
 	}
 }
 
+#[cfg(feature = "std")]
 pub mod compls_p2 {
 	use serde::{Deserialize, Serializer, Deserializer, Serialize};
 	use geo::LineString;
@@ -188,6 +1067,7 @@ pub mod compls_p2 {
 	}
 }
 
+#[cfg(feature = "std")]
 pub mod compls_p7 {
 	/// Convenience
 	use serde::{Deserialize, Serializer, Deserializer, Serialize};
@@ -270,4 +1150,168 @@ mod compls_tests {
 			assert_ls_eq!(l, item2.data);
 		}
 	}
+
+	#[test]
+	fn compact_roundtrip_and_length() {
+		for l in [
+			wktls!(76.9017028 43.1802978),
+			wktls!(76.8936157 43.2443809,76.8936309 43.2442245),
+			wktls!(76.8397903 43.2167510,76.8398132 43.2167587,76.8408584 43.2169990),
+			wktls!(76.9756393 43.2715377,76.9760818 43.2720947,76.9766235 43.2728042),
+			wktls!(76.9615707 43.2746200,76.9616699 43.2747688,76.9620742 43.2753715,76.9627532 43.2764091,76.9629516 43.2765502,76.9630584 43.2765998),
+			wktls!(76.9759140 43.2704200,76.9757766 43.2705001,76.9756774 43.2705917,76.9755706 43.2707099,76.9754562 43.2708740,76.9753875 43.2710494,76.9754028 43.2711601,76.9754638 43.2713012,76.9756011 43.2714843,76.9756393 43.2715377),
+		] {
+			let length = l.0.len();
+			let compln = CompLs::try_encode_compact(&l, Precision::Seven).unwrap();
+			assert_eq!(length, compln.size_compact().unwrap());
+
+			let reparsed = CompLs::try_new_compact(&compln.coords).unwrap();
+			let decoded = reparsed.linestring_compact(Precision::Seven).unwrap();
+			assert_ls_eq!(l, decoded);
+		}
+	}
+
+	#[test]
+	fn compact_decode_rejects_truncated_big_int_header() {
+		// header byte `0b0000_0111` claims a 5-byte big-int payload but the buffer holds none.
+		let broken = CompLs { coords: vec![0b0000_0111] };
+		assert!(matches!(broken.linestring_compact(Precision::Seven), Err(CompLsError::BrokenEncoding(_))));
+	}
+
+	#[test]
+	fn compact_decode_rejects_oversized_big_int_header() {
+		// header byte `0b0001_0111` claims a 9-byte big-int payload, which can't have come
+		// from `encode_compact_int` (max 8 bytes) and would overflow its fixed-size buffer.
+		let mut coords = vec![0b0001_0111];
+		coords.extend_from_slice(&[0_u8; 9]);
+		let broken = CompLs { coords };
+		assert!(matches!(broken.linestring_compact(Precision::Seven), Err(CompLsError::BrokenEncoding(_))));
+	}
+
+	#[test]
+	fn packed_roundtrip_and_length() {
+		for l in [
+			wktls!(76.9017028 43.1802978),
+			wktls!(76.8936157 43.2443809,76.8936309 43.2442245),
+			wktls!(76.8397903 43.2167510,76.8398132 43.2167587,76.8408584 43.2169990),
+			wktls!(76.9756393 43.2715377,76.9760818 43.2720947,76.9766235 43.2728042),
+			wktls!(76.9615707 43.2746200,76.9616699 43.2747688,76.9620742 43.2753715,76.9627532 43.2764091,76.9629516 43.2765502,76.9630584 43.2765998),
+			wktls!(76.9759140 43.2704200,76.9757766 43.2705001,76.9756774 43.2705917,76.9755706 43.2707099,76.9754562 43.2708740,76.9753875 43.2710494,76.9754028 43.2711601,76.9754638 43.2713012,76.9756011 43.2714843,76.9756393 43.2715377),
+			// axis-aligned: every y-delta after the first is 0, but the first is the start's
+			// absolute y, so by stays nonzero; this checks the roundtrip, not the width.
+			wktls!(76.9000000 43.2000000,76.9010000 43.2000000,76.9020000 43.2000000,76.9030000 43.2000000),
+		] {
+			let length = l.0.len();
+			let compln = CompLs::try_encode_packed(&l, Precision::Seven).unwrap();
+			assert_eq!(length, compln.size_packed().unwrap());
+
+			let reparsed = CompLs::try_new_packed(&compln.coords).unwrap();
+			let decoded = reparsed.linestring_packed(Precision::Seven).unwrap();
+			assert_ls_eq!(l, decoded);
+		}
+	}
+
+	#[test]
+	fn packed_decode_rejects_corrupt_header() {
+		let broken = CompLs { coords: vec![0xFF, 0xFF, 0xFF] };
+		assert!(matches!(broken.size_packed(), Err(CompLsError::BrokenEncoding(_))));
+		assert!(matches!(broken.linestring_packed(Precision::Seven), Err(CompLsError::BrokenEncoding(_))));
+	}
+
+	#[test]
+	fn packed_decode_rejects_negative_vertex_count() {
+		// header byte `0b0000_0100` decodes (via zigzag) to a vertex count of -1, which must
+		// not wrap into a huge `usize` and sail through as "valid".
+		let broken = CompLs { coords: vec![0b0000_0100] };
+		assert!(matches!(broken.size_packed(), Err(CompLsError::BrokenEncoding(_))));
+		assert!(matches!(broken.linestring_packed(Precision::Seven), Err(CompLsError::BrokenEncoding(_))));
+	}
+
+	#[test]
+	fn streaming_roundtrip() {
+		for l in [
+			wktls!(76.9017028 43.1802978),
+			wktls!(76.8936157 43.2443809,76.8936309 43.2442245),
+			wktls!(76.8397903 43.2167510,76.8398132 43.2167587,76.8408584 43.2169990),
+			wktls!(76.9756393 43.2715377,76.9760818 43.2720947,76.9766235 43.2728042),
+		] {
+			let mut buf: Vec<u8> = vec![];
+			encode_to(&l, Precision::Seven, &mut buf).unwrap();
+
+			let mut cursor = &buf[..];
+			let decoded: Result<Vec<Coord>, CompLsError> = decode_from(&mut cursor, Precision::Seven).collect();
+			let decoded = LineString(decoded.unwrap());
+			assert_ls_eq!(l, decoded);
+		}
+	}
+
+	#[test]
+	fn streaming_decode_reports_truncated_varint() {
+		let l = wktls!(76.9017028 43.1802978,76.8936157 43.2443809);
+		let mut buf: Vec<u8> = vec![];
+		encode_to(&l, Precision::Seven, &mut buf).unwrap();
+		buf.truncate(buf.len() - 1); // cut the last varint short
+
+		let mut cursor = &buf[..];
+		let result: Result<Vec<Coord>, CompLsError> = decode_from(&mut cursor, Precision::Seven).collect();
+		assert!(matches!(result, Err(CompLsError::BrokenEncoding(_))));
+	}
+
+	#[test]
+	fn compgeom_multi_line_string_roundtrip() {
+		let mls = MultiLineString(vec![
+			wktls!(76.9017028 43.1802978,76.8936157 43.2443809),
+			wktls!(76.8397903 43.2167510,76.8398132 43.2167587,76.8408584 43.2169990),
+		]);
+		let compg = mls.try_compact7().unwrap();
+		let DecodedGeom::MultiLineString(decoded) = compg.geometry(Precision::Seven).unwrap() else { panic!("expected MultiLineString") };
+		assert_eq!(mls.0.len(), decoded.0.len());
+		for (orig, back) in mls.0.iter().zip(decoded.0.iter()) {
+			assert_ls_eq!(orig.clone(), back.clone());
+		}
+
+		#[derive(Serialize, Deserialize)]
+		struct SerializeTest {
+			#[serde(with = "compgeom_mls_p7")]
+			pub data: MultiLineString,
+		}
+		let data = bincode::serialize(&SerializeTest { data: mls.clone() }).unwrap();
+		let item2: SerializeTest = bincode::deserialize(&data).unwrap();
+		assert_ls_eq!(mls.0[0].clone(), item2.data.0[0].clone());
+	}
+
+	#[test]
+	fn compgeom_polygon_with_hole_roundtrip() {
+		let exterior = wktls!(0.0 0.0, 10.0 0.0, 10.0 10.0, 0.0 10.0, 0.0 0.0);
+		let hole = wktls!(2.0 2.0, 2.0 4.0, 4.0 4.0, 4.0 2.0, 2.0 2.0);
+		let poly = Polygon::new(exterior.clone(), vec![hole.clone()]);
+
+		let compg = poly.try_compact2().unwrap();
+		let DecodedGeom::Polygon(decoded) = compg.geometry(Precision::Two).unwrap() else { panic!("expected Polygon") };
+		assert_ls_eq!(exterior, decoded.exterior().clone());
+		assert_eq!(decoded.interiors().len(), 1);
+		assert_ls_eq!(hole, decoded.interiors()[0].clone());
+	}
+
+	#[test]
+	fn compgeom_multi_polygon_roundtrip() {
+		let poly1 = Polygon::new(wktls!(0.0 0.0, 1.0 0.0, 1.0 1.0, 0.0 1.0, 0.0 0.0), vec![]);
+		let poly2 = Polygon::new(wktls!(5.0 5.0, 6.0 5.0, 6.0 6.0, 5.0 6.0, 5.0 5.0), vec![]);
+		let mpoly = MultiPolygon(vec![poly1, poly2]);
+
+		let compg = mpoly.try_compact2().unwrap();
+		let DecodedGeom::MultiPolygon(decoded) = compg.geometry(Precision::Two).unwrap() else { panic!("expected MultiPolygon") };
+		assert_eq!(mpoly.0.len(), decoded.0.len());
+		for (orig, back) in mpoly.0.iter().zip(decoded.0.iter()) {
+			assert_ls_eq!(orig.exterior().clone(), back.exterior().clone());
+		}
+	}
+
+	#[test]
+	fn compgeom_geometry_rejects_truncated_ring() {
+		// multi-line-string tag, 1 part, vertex count 1, then a varint continuation byte
+		// with nothing after it.
+		let broken = CompGeom { bytes: vec![COMP_GEOM_TAG_MULTI_LINE_STRING, 0b0000_1000, 0b0000_1000, 128] };
+		assert!(matches!(broken.geometry(Precision::Seven), Err(CompLsError::BrokenEncoding(_))));
+	}
 }
\ No newline at end of file